@@ -1,4 +1,5 @@
-use std::{os::unix::thread::JoinHandleExt, sync::atomic::AtomicUsize, thread};
+#![cfg_attr(feature = "atomic128", feature(integer_atomics))]
+use std::{os::unix::thread::JoinHandleExt, thread};
 
 use thread_priority::{
     set_thread_priority, thread_native_id, RealtimeThreadSchedulePolicy, ThreadPriority,
@@ -46,26 +47,85 @@ fn set_priority(priority: ThreadPriority) {
 
 extern crate alloc;
 
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 use core::{
+    cell::UnsafeCell,
+    marker::PhantomData,
     ops::{Deref, DerefMut},
     ptr,
     sync::atomic::{
-        AtomicPtr,
-        Ordering::{Acquire, Relaxed, Release},
+        AtomicPtr, AtomicU64, AtomicUsize,
+        Ordering::{AcqRel, Acquire, Relaxed, Release},
     },
 };
 
+/// Source of per-thread identifiers for the owner fast path.
+///
+/// Id `0` is reserved to mean "unowned", so we start handing out ids at `1`.
+static NEXT_THREAD_ID: AtomicU64 = AtomicU64::new(1);
+
+std::thread_local! {
+    static THREAD_ID: u64 = NEXT_THREAD_ID.fetch_add(1, Relaxed);
+}
+
+fn thread_id() -> u64 {
+    THREAD_ID.with(|id| *id)
+}
+
 pub struct Pool<T, F = fn() -> T> {
     create: F,
-    /// Pointer to the head of the linked list of free nodes.
+    /// The free list, split across one or more independent Treiber stacks.
+    ///
+    /// Each stack head is a `(ptr, tag)` pair packed into one atomic word
+    /// (double-width on the `atomic128` path, a single `usize` with the tag in
+    /// the pointer's alignment bits otherwise; see `head_impl`): `ptr` is the
+    /// head node (null if the stack is empty) and `tag` is a version counter
+    /// that is bumped on every push and pop. Comparing the whole word in the CAS is
+    /// what defeats the ABA problem, so popping never needs to lock the list
+    /// or spin on a sentinel.
+    ///
+    /// A thread only ever contends on the single shard chosen for it, so
+    /// throughput scales with shard count when many threads pile on at once.
+    shards: Box<[Head<T>]>,
+    /// Id of the thread that owns the fast path, or `0` if unclaimed.
+    ///
+    /// The overwhelmingly common access pattern is a single thread calling
+    /// `get()`/drop in a loop; for that thread we keep one node in
+    /// [`Pool::owner_node`] and skip the shared stack entirely. Ownership is
+    /// not sticky-for-life: a thread relinquishes it (back to `0`) the moment a
+    /// drop finds the slot already occupied, so under genuine concurrency the
+    /// slot is not monopolized by whichever thread happened to call first.
+    owner: AtomicU64,
+    /// The node cached for the owning thread. Only ever touched by the thread
+    /// whose id currently equals `owner`.
+    owner_node: UnsafeCell<Option<*mut Node<T>>>,
+    /// Maximum number of free nodes retained in the shared shards.
     ///
-    /// Null if there are no free nodes.
+    /// `usize::MAX` means unbounded. Once [`Pool::len`] reaches this, a dropped
+    /// guard frees its node instead of pushing it back (provided no popper is
+    /// in flight on its shard, see [`Pool::poppers`]), capping steady-state
+    /// memory after a transient load spike inflates the live-object count.
+    capacity: usize,
+    /// Approximate number of free nodes currently parked in the shards.
     ///
-    /// LOCKED if the list is locked, which only happens briefly
-    /// when removing a node, not when adding a node back or when
-    /// allocating a new node.
-    head: AtomicPtr<Node<T>>,
+    /// Only advisory under contention — it bounds steady-state memory, it is
+    /// not an exact invariant.
+    len: AtomicUsize,
+    /// Per-shard count of threads currently inside the lock-free pop window,
+    /// i.e. between snapshotting that shard's head and finishing their
+    /// compare-exchange.
+    ///
+    /// The bounded-capacity path uses this as a lightweight reclamation guard:
+    /// a node is only `Box::from_raw`-freed while its shard's counter is zero.
+    /// A popper bumps it *before* loading the head, so a node already off the
+    /// shard cannot be snapshotted by any popper that starts afterwards, and a
+    /// zero count means every earlier popper on that shard has finished
+    /// dereferencing. Freeing while a popper is in flight would risk it reading
+    /// `next` off a node we just deallocated.
+    ///
+    /// The counter is per-shard rather than pool-wide so that a popper parked
+    /// in its backoff on one shard does not suspend reclamation on the others.
+    poppers: Box<[AtomicUsize]>,
 }
 
 // Safety: Using the same Pool from multiple fines is fine as
@@ -76,19 +136,247 @@ unsafe impl<T: Send, F: Sync> Sync for Pool<T, F> {}
 // and F allow that.
 unsafe impl<T: Send, F: Send> Send for Pool<T, F> {}
 
-/// Special value we use for the `head` pointer to incicate that the pool is locked.
-const LOCKED: *mut Node<()> = usize::MAX as *mut _;
-
 struct Node<T> {
     next: AtomicPtr<Node<T>>,
+    /// Live `PoolRef` count for the shared-reference path.
+    ///
+    /// Unused by the exclusive `PoolGuard` path, which owns the node outright.
+    /// The node returns to the free list when this drops back to zero.
+    rc: AtomicUsize,
     value: T,
 }
 
 impl<T, F> Pool<T, F> {
     pub fn new(create: F) -> Pool<T, F> {
+        Pool::build(create, 1, usize::MAX)
+    }
+
+    /// Create a pool whose free list is split across `n` independent stacks.
+    ///
+    /// Each `get()` only contends on the one shard chosen for the calling
+    /// thread (by its id), which cuts the cache-line ping-pong that a single
+    /// shared head suffers when hundreds of threads hammer the pool at once.
+    /// `n` is clamped to at least `1`.
+    pub fn with_shards(create: F, n: usize) -> Pool<T, F> {
+        Pool::build(create, n, usize::MAX)
+    }
+
+    /// Create a pool that retains at most `capacity` free nodes.
+    ///
+    /// When a guard is dropped while the free list is already full, its node
+    /// is freed instead of being returned, so a transient spike in live
+    /// objects does not permanently inflate the pool's memory footprint.
+    ///
+    /// The bound is enforced at shard quiescence: a node can only be freed
+    /// while no other thread is mid-pop on the same shard (otherwise a popper
+    /// might still dereference it), so under sustained contention a shard may
+    /// temporarily retain more than `capacity` nodes and trims back down once
+    /// the contention subsides.
+    pub fn with_capacity(create: F, capacity: usize) -> Pool<T, F> {
+        Pool::build(create, 1, capacity)
+    }
+
+    fn build(create: F, n: usize, capacity: usize) -> Pool<T, F> {
+        let n = n.max(1);
+        let mut shards = Vec::with_capacity(n);
+        shards.resize_with(n, || Head::new(ptr::null_mut()));
+        let mut poppers = Vec::with_capacity(n);
+        poppers.resize_with(n, || AtomicUsize::new(0));
         Pool {
             create,
-            head: AtomicPtr::new(ptr::null_mut()),
+            shards: shards.into_boxed_slice(),
+            owner: AtomicU64::new(0),
+            owner_node: UnsafeCell::new(None),
+            capacity,
+            len: AtomicUsize::new(0),
+            poppers: poppers.into_boxed_slice(),
+        }
+    }
+
+    /// Index of the shard a thread contends on, derived from its id.
+    fn shard_index(&self, id: u64) -> usize {
+        (id % self.shards.len() as u64) as usize
+    }
+}
+
+/// Exponential backoff for contended compare-exchange retries.
+///
+/// Each [`Backoff::snooze`] spins a little longer than the last. By default it
+/// escalates from `spin_loop` hints to `yield_now` and finally a short
+/// `park_timeout`, so a descheduled holder of the cache line actually gets to
+/// run — which is what keeps these spins from degenerating into the
+/// priority-inversion "spin of death" under realtime scheduling. The
+/// scheduler-aware path is the one that compiles for this `std` binary; only
+/// an explicit `no_std` build (which has nothing to yield to) falls back to a
+/// bounded pure spin.
+struct Backoff {
+    step: u32,
+}
+
+impl Backoff {
+    const SPIN_LIMIT: u32 = 6;
+    const YIELD_LIMIT: u32 = 10;
+
+    fn new() -> Backoff {
+        Backoff { step: 0 }
+    }
+
+    fn snooze(&mut self) {
+        if self.step <= Self::SPIN_LIMIT {
+            for _ in 0..(1u32 << self.step) {
+                core::hint::spin_loop();
+            }
+        } else {
+            #[cfg(not(feature = "no_std"))]
+            {
+                if self.step <= Self::YIELD_LIMIT {
+                    std::thread::yield_now();
+                } else {
+                    std::thread::park_timeout(core::time::Duration::from_micros(50));
+                }
+            }
+            // Without a scheduler to yield to, keep spinning at the cap.
+            #[cfg(feature = "no_std")]
+            for _ in 0..(1u32 << Self::SPIN_LIMIT) {
+                core::hint::spin_loop();
+            }
+        }
+        if self.step <= Self::YIELD_LIMIT {
+            self.step += 1;
+        }
+    }
+}
+
+/// A version counter used to tag the head pointer against ABA.
+///
+/// On the double-word path the tag has the full range of a `usize`; on the
+/// fallback path it only spans the stolen alignment bits and wraps much
+/// sooner, but that is still enough to make an ABA collision vanishingly
+/// unlikely in practice.
+type Tag = usize;
+
+use self::head_impl::Head;
+
+/// Double-word `(ptr, tag)` head, backed by a 128-bit atomic.
+///
+/// This is the `cmpxchg16b`/`casp` path: the pointer lives in the low 64
+/// bits and the tag in the high 64 bits, and every update touches the whole
+/// word in a single atomic compare-exchange.
+///
+/// `AtomicU128` is still unstable, so this path is behind the off-by-default
+/// `atomic128` feature and only compiles on a nightly toolchain whose target
+/// advertises a 128-bit CAS. Everything else — including every stable build —
+/// uses the tagged-pointer fallback below, which is why that fallback is the
+/// default that gets exercised.
+#[cfg(all(target_has_atomic = "128", feature = "atomic128"))]
+mod head_impl {
+    use super::{Node, PhantomData, Tag};
+    use core::sync::atomic::{AtomicU128, Ordering};
+
+    pub(super) struct Head<T> {
+        word: AtomicU128,
+        _marker: PhantomData<*mut Node<T>>,
+    }
+
+    const PTR_MASK: u128 = u64::MAX as u128;
+
+    fn pack<T>(ptr: *mut Node<T>, tag: Tag) -> u128 {
+        (ptr as usize as u128) | ((tag as u128) << 64)
+    }
+
+    fn unpack<T>(word: u128) -> (*mut Node<T>, Tag) {
+        (((word & PTR_MASK) as usize) as *mut Node<T>, (word >> 64) as Tag)
+    }
+
+    impl<T> Head<T> {
+        pub(super) fn new(ptr: *mut Node<T>) -> Head<T> {
+            Head { word: AtomicU128::new(pack(ptr, 0)), _marker: PhantomData }
+        }
+
+        pub(super) fn load(&self, order: Ordering) -> (*mut Node<T>, Tag) {
+            unpack(self.word.load(order))
+        }
+
+        pub(super) fn compare_exchange_weak(
+            &self,
+            current: (*mut Node<T>, Tag),
+            new: (*mut Node<T>, Tag),
+            success: Ordering,
+            failure: Ordering,
+        ) -> Result<(), (*mut Node<T>, Tag)> {
+            self.word
+                .compare_exchange_weak(pack(current.0, current.1), pack(new.0, new.1), success, failure)
+                .map(|_| ())
+                .map_err(unpack)
+        }
+
+        pub(super) fn get_mut(&mut self) -> *mut Node<T> {
+            unpack(*self.word.get_mut()).0
+        }
+    }
+}
+
+/// Single-word fallback for targets without a (stable) 128-bit CAS.
+///
+/// `Node<T>` is aligned to at least `align_of::<Node<T>>()`, so the low bits
+/// of a real node pointer below that alignment are always zero and we reuse
+/// them to carry the version tag. The number of usable bits is derived from
+/// the alignment at runtime rather than hard-coded, so it stays correct on
+/// 32-bit targets (where `Node<T>` may align to 4, leaving only two tag bits)
+/// as well as 64-bit ones. The tag range is tiny, but an ABA slip additionally
+/// requires the exact same node address to be recycled within that window,
+/// which is rare enough for our purposes.
+#[cfg(not(all(target_has_atomic = "128", feature = "atomic128")))]
+mod head_impl {
+    use super::{Node, PhantomData, Tag};
+    use core::mem::align_of;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    pub(super) struct Head<T> {
+        word: AtomicUsize,
+        _marker: PhantomData<*mut Node<T>>,
+    }
+
+    /// Bits stealable from a `*mut Node<T>`, i.e. those below its alignment.
+    fn tag_mask<T>() -> usize {
+        align_of::<Node<T>>() - 1
+    }
+
+    fn pack<T>(ptr: *mut Node<T>, tag: Tag) -> usize {
+        let mask = tag_mask::<T>();
+        debug_assert!(ptr as usize & mask == 0, "Node is under-aligned");
+        (ptr as usize & !mask) | (tag & mask)
+    }
+
+    fn unpack<T>(word: usize) -> (*mut Node<T>, Tag) {
+        let mask = tag_mask::<T>();
+        ((word & !mask) as *mut Node<T>, word & mask)
+    }
+
+    impl<T> Head<T> {
+        pub(super) fn new(ptr: *mut Node<T>) -> Head<T> {
+            Head { word: AtomicUsize::new(pack(ptr, 0)), _marker: PhantomData }
+        }
+
+        pub(super) fn load(&self, order: Ordering) -> (*mut Node<T>, Tag) {
+            unpack(self.word.load(order))
+        }
+
+        pub(super) fn compare_exchange_weak(
+            &self,
+            current: (*mut Node<T>, Tag),
+            new: (*mut Node<T>, Tag),
+            success: Ordering,
+            failure: Ordering,
+        ) -> Result<(), (*mut Node<T>, Tag)> {
+            self.word
+                .compare_exchange_weak(pack(current.0, current.1), pack(new.0, new.1), success, failure)
+                .map(|_| ())
+                .map_err(unpack)
+        }
+
+        pub(super) fn get_mut(&mut self) -> *mut Node<T> {
+            unpack(*self.word.get_mut()).0
         }
     }
 }
@@ -96,6 +384,8 @@ impl<T, F> Pool<T, F> {
 pub struct PoolGuard<'a, T, F> {
     pool: &'a Pool<T, F>,
     node: *mut Node<T>,
+    /// Shard this node is returned to on drop (unless the owner slot takes it).
+    shard: usize,
 }
 
 // Safety: Sharing a PoolGuard with another thread effectively
@@ -109,82 +399,185 @@ unsafe impl<T: Send, F> Send for PoolGuard<'_, T, F> {}
 
 impl<T, F: Fn() -> T> Pool<T, F> {
     pub fn get(&self) -> PoolGuard<'_, T, F> {
-        let mut node = self.head.load(Relaxed);
-        while !node.is_null() {
-            if node == LOCKED.cast() {
-                // Locked! Try again!
-                core::hint::spin_loop();
-                node = self.head.load(Relaxed);
-                continue;
+        let (node, shard) = self.checkout();
+        PoolGuard { pool: self, node, shard }
+    }
+
+    /// Borrow a pooled object by shared reference rather than exclusively.
+    ///
+    /// The returned [`PoolRef`] hands out `&T` and is cheaply cloneable, so
+    /// several borrowers — typically on the same thread — can read the same
+    /// pooled object at once. The object only returns to the free list once
+    /// the last `PoolRef` for it is dropped. This fits immutable,
+    /// expensive-to-build resources (a compiled table, a warm buffer, a
+    /// connection handle); if you need to mutate, pool a `RefCell<T>`.
+    pub fn get_ref(&self) -> PoolRef<'_, T, F>
+    where
+        T: Sync,
+    {
+        let (node, shard) = self.checkout();
+        // We are the sole referrer to this freshly checked-out node.
+        // Safety: we exclusively own it until the first `PoolRef` exists.
+        unsafe { (*node).rc.store(1, Relaxed) };
+        PoolRef { pool: self, node, shard }
+    }
+
+    /// Take a node off the pool, returning it together with the shard it
+    /// should be checked back in to.
+    fn checkout(&self) -> (*mut Node<T>, usize) {
+        // Fast path: if we are (or can become) the owning thread, take the
+        // cached node without touching the shared stack at all.
+        let id = thread_id();
+        let shard = self.shard_index(id);
+        let owner = self.owner.load(Acquire);
+        if owner == id
+            || (owner == 0 && self.owner.compare_exchange(0, id, AcqRel, Acquire).is_ok())
+        {
+            // Safety: only the owning thread ever accesses `owner_node`.
+            if let Some(node) = unsafe { &mut *self.owner_node.get() }.take() {
+                return (node, shard);
             }
-            // Take the head node and lock the list.
-            // We need to briefly lock the list, so we have time to check the
-            // `next` pointer of the head node without it changing.
-            // (If we check the `next` pointer before taking the node,
-            // we could run into the ABA problem.)
-            match self
-                .head
-                .compare_exchange_weak(node, LOCKED.cast(), Acquire, Relaxed)
-            {
-                Ok(_) => {
-                    // Safety: we swapped the head pointer to LOCKED, so we now
-                    // exclusively own this node.
-                    let next = unsafe { *(*node).next.get_mut() };
-                    // Unlock the list and put the next node back as the head.
-                    // We use release ordering here, to make sure that a future
-                    // acquire-load of the head pointer still synchronizes with
-                    // the release operation that originally stored the pointer
-                    // to that node.
-                    // (Alternatively, we could use a relaxed swap here.)
-
-                    while BARRIER.load(std::sync::atomic::Ordering::Relaxed) < N_THREADS {}
-
-                    self.head.store(next, Release);
-                    return PoolGuard { pool: self, node };
+            // Slot empty; fall through to the shared stack / allocation.
+        }
+        // Classic Treiber-stack pop on our shard, tagged head to sidestep ABA.
+        //
+        // Register as an in-flight popper *before* snapshotting the head, so
+        // that `checkin` cannot free a node that we might be about to read
+        // `next` from (see `Pool::poppers`). The count is dropped again on
+        // every exit path out of the pop window.
+        let head = &self.shards[shard];
+        let poppers = &self.poppers[shard];
+        let mut backoff = Backoff::new();
+        poppers.fetch_add(1, AcqRel);
+        let (mut old_ptr, mut old_tag) = head.load(Acquire);
+        while !old_ptr.is_null() {
+            // Safety: a node still reachable from the stack is never freed, and
+            // the `poppers` guard keeps a node from being freed out from under
+            // us in the window after it is popped, so reading `next` off the
+            // current head is sound even if another thread is about to pop it.
+            // If it does, our compare exchange of the whole `(ptr, tag)` word
+            // fails and we retry — the tag having changed is exactly what makes
+            // an ABA pop/re-push observable, so we never hand out a stale
+            // `next`.
+            let next = unsafe { (*old_ptr).next.load(Relaxed) };
+            match head.compare_exchange_weak(
+                (old_ptr, old_tag),
+                (next, old_tag.wrapping_add(1)),
+                Acquire,
+                Acquire,
+            ) {
+                Ok(()) => {
+                    poppers.fetch_sub(1, Release);
+                    self.len.fetch_sub(1, Relaxed);
+                    return (old_ptr, shard);
+                }
+                // The head changed under us; back off and retry.
+                Err((ptr, tag)) => {
+                    backoff.snooze();
+                    old_ptr = ptr;
+                    old_tag = tag;
                 }
-                // The head pointer changed, so we need to try again.
-                Err(head) => node = head,
             }
         }
+        poppers.fetch_sub(1, Release);
         // No free node currently available. Allocate a new one.
-        PoolGuard {
-            pool: self,
-            node: Box::into_raw(Box::new(Node {
-                next: AtomicPtr::new(ptr::null_mut()),
-                value: (self.create)(),
-            })),
-        }
+        let node = Box::into_raw(Box::new(Node {
+            next: AtomicPtr::new(ptr::null_mut()),
+            rc: AtomicUsize::new(0),
+            value: (self.create)(),
+        }));
+        (node, shard)
     }
 }
 
-impl<'a, T, F> Drop for PoolGuard<'a, T, F> {
-    fn drop(&mut self) {
-        let mut head = self.pool.head.load(Relaxed);
+impl<T, F> Pool<T, F> {
+    /// Return a node to the pool, honoring the owner fast path and capacity.
+    ///
+    /// # Safety
+    ///
+    /// The caller must own `node` exclusively (no other guard or `PoolRef`
+    /// still refers to it) and must pass the `shard` it was checked out from.
+    unsafe fn checkin(&self, node: *mut Node<T>, shard: usize) {
+        // Fast path: if this is the owning thread and its slot is free, stash
+        // the node there instead of going through the shared stack. We re-read
+        // the owner id here because guards are `Send` and may be dropped on a
+        // different thread than the one that created them.
+        if thread_id() == self.owner.load(Acquire) {
+            // Safety: only the owning thread ever accesses `owner_node`.
+            let slot = unsafe { &mut *self.owner_node.get() };
+            if slot.is_none() {
+                *slot = Some(node);
+                return;
+            }
+            // The slot is already full, so ownership buys us nothing for this
+            // drop and keeping it would starve every other thread for the rest
+            // of the pool's life. Relinquish it (leaving the cached node in the
+            // slot for whoever claims next) and fall through to the shared
+            // stack. The `Release` here pairs with the `Acquire` on the claim
+            // CAS in `checkout`, so the next owner sees the node we left behind.
+            self.owner.store(0, Release);
+        }
+        // Treiber-stack push onto our shard, bumping the tag so that a
+        // concurrent popper sees a fresh word even if our node reuses a
+        // recently freed address.
+        let head = &self.shards[shard];
+        let poppers = &self.poppers[shard];
+        let mut backoff = Backoff::new();
+        let (mut head_ptr, mut head_tag) = head.load(Relaxed);
         loop {
-            if head == LOCKED.cast() {
-                // Locked! Try again!
-                core::hint::spin_loop();
-                head = self.pool.head.load(Relaxed);
-                continue;
+            // Respect the capacity cap: if the free list is already full, free
+            // the node rather than retaining it forever. We may only
+            // deallocate while no popper is in flight on this shard —
+            // otherwise a popper that snapshotted this node as the shard head
+            // before we popped it could still be about to read its `next`.
+            // When a popper is in flight we conservatively push the node back
+            // instead; `len` is advisory, so briefly overshooting the cap is
+            // fine and the next quiescent drop trims it back down.
+            // An unbounded pool never frees here: `len` can transiently wrap
+            // to `usize::MAX` when a pop's decrement beats the push's increment.
+            if self.capacity != usize::MAX
+                && self.len.load(Relaxed) >= self.capacity
+                && poppers.load(Acquire) == 0
+            {
+                // Safety: the node came from `Box::into_raw`, the caller owns
+                // it exclusively, no popper can hold a stale pointer to it
+                // (this shard's `poppers == 0`), and this is its last use.
+                drop(unsafe { Box::from_raw(node) });
+                return;
             }
             // Point our node's next pointer to the head of the list.
-            // Safety: We haven't given it back to the pool yet, so we still
-            // exclusively own this node.
-            unsafe { *(*self.node).next.get_mut() = head };
+            // Safety: the caller still exclusively owns this node.
+            unsafe { (*node).next.store(head_ptr, Relaxed) };
             // Try to put our node back as the head of the list,
-            // if the head pointer is (still) the same.
-            match self
-                .pool
-                .head
-                .compare_exchange_weak(head, self.node, Release, Relaxed)
-            {
-                Ok(_) => return,
-                Err(p) => head = p,
+            // if the head word is (still) the same.
+            match head.compare_exchange_weak(
+                (head_ptr, head_tag),
+                (node, head_tag.wrapping_add(1)),
+                Release,
+                Relaxed,
+            ) {
+                Ok(()) => {
+                    self.len.fetch_add(1, Relaxed);
+                    return;
+                }
+                Err((ptr, tag)) => {
+                    backoff.snooze();
+                    head_ptr = ptr;
+                    head_tag = tag;
+                }
             }
         }
     }
 }
 
+impl<'a, T, F> Drop for PoolGuard<'a, T, F> {
+    fn drop(&mut self) {
+        // Safety: the guard owned `self.node` exclusively and hands it back
+        // once, to the shard it was checked out from.
+        unsafe { self.pool.checkin(self.node, self.shard) };
+    }
+}
+
 impl<'a, T, F> Deref for PoolGuard<'a, T, F> {
     type Target = T;
 
@@ -201,18 +594,74 @@ impl<'a, T, F> DerefMut for PoolGuard<'a, T, F> {
     }
 }
 
+/// A shared, reference-counted borrow of a pooled object, handed out by
+/// [`Pool::get_ref`].
+///
+/// Unlike [`PoolGuard`], this grants only `&T` and is cheaply cloneable, so
+/// several borrowers can read the same object at once. The node returns to the
+/// pool once the last clone is dropped.
+pub struct PoolRef<'a, T, F> {
+    pool: &'a Pool<T, F>,
+    node: *mut Node<T>,
+    /// Shard this node is returned to once the last clone is dropped.
+    shard: usize,
+}
+
+// Safety: A PoolRef only ever exposes `&T` and the node is refcounted, so
+// sharing or moving it across threads is sound exactly when `T` can be shared
+// across threads by shared reference.
+unsafe impl<T: Sync, F> Sync for PoolRef<'_, T, F> {}
+unsafe impl<T: Sync, F> Send for PoolRef<'_, T, F> {}
+
+impl<'a, T, F> Clone for PoolRef<'a, T, F> {
+    fn clone(&self) -> PoolRef<'a, T, F> {
+        // Safety: we hold a live reference, so the refcount is at least one.
+        unsafe { (*self.node).rc.fetch_add(1, Relaxed) };
+        PoolRef { pool: self.pool, node: self.node, shard: self.shard }
+    }
+}
+
+impl<'a, T, F> Deref for PoolRef<'a, T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // Safety: the node stays alive while any PoolRef refers to it.
+        unsafe { &(*self.node).value }
+    }
+}
+
+impl<'a, T, F> Drop for PoolRef<'a, T, F> {
+    fn drop(&mut self) {
+        // Release ensures every borrower's reads happen-before the node is
+        // handed back; the Acquire fence on the final drop pairs with them.
+        if unsafe { (*self.node).rc.fetch_sub(1, Release) } != 1 {
+            return;
+        }
+        core::sync::atomic::fence(Acquire);
+        // Safety: we were the last referrer, so the node is ours to return.
+        unsafe { self.pool.checkin(self.node, self.shard) };
+    }
+}
+
 impl<T, F> Drop for Pool<T, F> {
     fn drop(&mut self) {
-        let mut node = *self.head.get_mut();
-        while !node.is_null() {
-            // Safety: We have exclusive access to the pool now (&mut self),
-            // including all the nodes, so there is no need for any synchronization.
-            // So, we can just use .get_mut() on the atomics.
-            let next = unsafe { *(*node).next.get_mut() };
-            // Safety: This pointer came from Box::into_raw, we have exclusive access
-            // to the node, and this is the last time this pointer will be used.
+        // Free the node cached in the owner slot, if any.
+        if let Some(node) = self.owner_node.get_mut().take() {
+            // Safety: exclusive access via `&mut self`; see below.
             drop(unsafe { Box::from_raw(node) });
-            node = next;
+        }
+        for head in self.shards.iter_mut() {
+            let mut node = head.get_mut();
+            while !node.is_null() {
+                // Safety: We have exclusive access to the pool now (&mut self),
+                // including all the nodes, so there is no need for any synchronization.
+                // So, we can just use .get_mut() on the atomics.
+                let next = unsafe { *(*node).next.get_mut() };
+                // Safety: This pointer came from Box::into_raw, we have exclusive access
+                // to the node, and this is the last time this pointer will be used.
+                drop(unsafe { Box::from_raw(node) });
+                node = next;
+            }
         }
     }
 }